@@ -1,12 +1,15 @@
+use std::collections::{HashMap, VecDeque};
 use std::fs;
-use std::io::{BufRead, BufReader};
+use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Stdio};
-use std::sync::Mutex;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::{mpsc, Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use serde::{Deserialize, Serialize};
-use tauri::{Manager, WebviewWindow};
+use tauri::{Emitter, Manager, WebviewUrl, WebviewWindow, WebviewWindowBuilder};
 
 // ---------------------------------------------------------------------------
 // Sidecar management
@@ -18,16 +21,67 @@ struct Sidecar {
     port: u16,
 }
 
+/// How long to wait for the sidecar's readiness line before giving up.
+const SIDECAR_STARTUP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Grace period to let the sidecar exit cleanly before we kill it.
+const SIDECAR_SHUTDOWN_GRACE: Duration = Duration::from_secs(5);
+
 impl Drop for Sidecar {
     fn drop(&mut self) {
         // Closing stdin signals the Python sidecar to shut down gracefully.
         // The sidecar's open_work_db context manager then stops Postgres.
         drop(self.process.stdin.take());
+
+        // Poll for a clean exit within the grace period, then escalate to
+        // SIGKILL so a wedged Postgres shutdown can't freeze app close.
+        let deadline = SystemTime::now() + SIDECAR_SHUTDOWN_GRACE;
+        loop {
+            match self.process.try_wait() {
+                Ok(Some(_)) => return,
+                Ok(None) => {
+                    if SystemTime::now() >= deadline {
+                        break;
+                    }
+                    thread::sleep(Duration::from_millis(100));
+                }
+                Err(_) => break,
+            }
+        }
+        let _ = self.process.kill();
         let _ = self.process.wait();
     }
 }
 
-struct SidecarState(Mutex<Option<Sidecar>>);
+/// Sidecars keyed by the Tauri window label, so each open window runs its
+/// own Python sidecar on its own port and several works can be open at once.
+struct SidecarState(Mutex<HashMap<String, Sidecar>>);
+
+/// Drop every remaining sidecar in parallel, one thread per sidecar, so
+/// shutting down N open windows costs one SIDECAR_SHUTDOWN_GRACE wait total
+/// rather than the HashMap's own Drop tearing them down one at a time
+/// (N * SIDECAR_SHUTDOWN_GRACE, which reintroduces the frozen-close problem
+/// SIDECAR_SHUTDOWN_GRACE was added to fix).
+fn shutdown_all_sidecars(state: &SidecarState) {
+    let sidecars = match state.0.lock() {
+        Ok(mut guard) => std::mem::take(&mut *guard),
+        Err(_) => return,
+    };
+
+    let handles: Vec<_> = sidecars
+        .into_values()
+        .map(|sidecar| thread::spawn(move || drop(sidecar)))
+        .collect();
+    for handle in handles {
+        let _ = handle.join();
+    }
+}
+
+/// Paths waiting to be opened by a freshly created work window, keyed by its
+/// window label. `open_work_window` stashes the target path here before the
+/// window exists; the new window's frontend calls `take_pending_work_path`
+/// on load to retrieve it and then calls `open_work` itself.
+static PENDING_WINDOW_PATHS: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
 
 /// Spawn the Python sidecar and wait for its readiness signal.
 fn spawn_sidecar(work_dir: &str) -> Result<Sidecar, String> {
@@ -36,6 +90,8 @@ fn spawn_sidecar(work_dir: &str) -> Result<Sidecar, String> {
 
     let mut child = Command::new(&python)
         .args(["-m", "littera.desktop.server", "--work-dir", work_dir])
+        .env_clear()
+        .envs(normalized_command_env())
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::inherit())
@@ -43,25 +99,66 @@ fn spawn_sidecar(work_dir: &str) -> Result<Sidecar, String> {
         .map_err(|e| format!("Failed to spawn sidecar ({python}): {e}"))?;
 
     let stdout = child.stdout.take().ok_or("No stdout from sidecar")?;
-    let reader = BufReader::new(stdout);
-
-    for line in reader.lines() {
-        let line = line.map_err(|e| format!("Failed to read sidecar stdout: {e}"))?;
-        if let Some(port_str) = line.strip_prefix("LITTERA_SIDECAR_READY:") {
-            let port: u16 = port_str
-                .trim()
-                .parse()
-                .map_err(|e| format!("Invalid port from sidecar: {e}"))?;
-            return Ok(Sidecar {
-                process: child,
-                port,
-            });
+
+    // Read stdout on a background thread so a wedged sidecar can't block the
+    // main path forever. The thread reports the discovered port (or a parse
+    // error) over a channel and then keeps forwarding output to the log sink.
+    let (tx, rx) = mpsc::channel::<Result<u16, String>>();
+    thread::spawn(move || {
+        let reader = BufReader::new(stdout);
+        let mut signaled = false;
+        for line in reader.lines() {
+            let line = match line {
+                Ok(l) => l,
+                Err(e) => {
+                    if !signaled {
+                        let _ = tx.send(Err(format!("Failed to read sidecar stdout: {e}")));
+                    }
+                    return;
+                }
+            };
+            if !signaled {
+                if let Some(port_str) = line.strip_prefix("LITTERA_SIDECAR_READY:") {
+                    let parsed = port_str
+                        .trim()
+                        .parse::<u16>()
+                        .map_err(|e| format!("Invalid port from sidecar: {e}"));
+                    let _ = tx.send(parsed);
+                    signaled = true;
+                    continue;
+                }
+            }
+            // Forward other lines (e.g. PG startup messages) to the log sink.
+            let (level, message) = parse_sidecar_level(&line);
+            record_log(level, "sidecar", message);
         }
-        // Forward other lines (e.g. PG startup messages) to stderr
-        eprintln!("[sidecar] {line}");
-    }
+    });
 
-    Err("Sidecar exited before signaling readiness".to_string())
+    match rx.recv_timeout(SIDECAR_STARTUP_TIMEOUT) {
+        Ok(Ok(port)) => Ok(Sidecar {
+            process: child,
+            port,
+        }),
+        Ok(Err(e)) => {
+            let _ = child.kill();
+            let _ = child.wait();
+            Err(e)
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            // stdout closed without a readiness line — the process exited early.
+            let _ = child.kill();
+            let _ = child.wait();
+            Err("Sidecar exited before signaling readiness".to_string())
+        }
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            let _ = child.kill();
+            let _ = child.wait();
+            Err(format!(
+                "Sidecar did not become ready within {}s",
+                SIDECAR_STARTUP_TIMEOUT.as_secs()
+            ))
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -108,19 +205,186 @@ fn find_python() -> String {
     "python3".to_string()
 }
 
+/// Describe which interpreter `find_python` resolved, for diagnostics.
+fn python_source() -> String {
+    let root = project_root();
+    if root.join(".venv/bin/python").exists() {
+        return "venv (.venv)".to_string();
+    }
+    if let Ok(venv_dir) = std::env::var("VIRTUAL_ENV") {
+        if Path::new(&venv_dir).join("bin/python").exists() {
+            return "VIRTUAL_ENV".to_string();
+        }
+    }
+    "system".to_string()
+}
+
 /// Check whether a directory looks like a Littera work (has .littera/ subdir).
 fn is_littera_work(path: &Path) -> bool {
     path.join(".littera").is_dir()
 }
 
+/// Percent-encode a filesystem path for use in a `file://` URI, leaving `/`
+/// unescaped so the path structure is preserved. Needed for paths with
+/// spaces or non-ASCII characters (e.g. author/project/year work folders),
+/// which `dbus-send`'s `ShowItems` would otherwise fail to resolve.
+fn percent_encode_path(path: &str) -> String {
+    let mut out = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    out
+}
+
+// ---------------------------------------------------------------------------
+// Environment normalization
+//
+// AppImage/Flatpak/Snap runtimes inject PATH, LD_LIBRARY_PATH, GStreamer/GTK
+// plugin paths and XDG_* entries that point at the bundle. Those leak into the
+// spawned `python -m littera.desktop.server` and break its ability to find a
+// clean system Python/Postgres, so we sanitize the child's environment.
+// ---------------------------------------------------------------------------
+
+/// Running from an AppImage (`APPIMAGE`/`APPDIR` are set by the runtime).
+fn is_appimage() -> bool {
+    std::env::var_os("APPIMAGE").is_some() || std::env::var_os("APPDIR").is_some()
+}
+
+/// Running inside a Flatpak sandbox.
+fn is_flatpak() -> bool {
+    Path::new("/.flatpak-info").exists()
+}
+
+/// Running inside a Snap (`SNAP` points at the mounted squashfs).
+fn is_snap() -> bool {
+    std::env::var_os("SNAP").is_some()
+}
+
+/// Directory prefixes injected by the active bundle runtime.
+fn bundle_prefixes() -> Vec<String> {
+    let mut prefixes = Vec::new();
+    for var in ["APPDIR", "SNAP", "FLATPAK_DEST"] {
+        if let Ok(v) = std::env::var(var) {
+            if !v.is_empty() {
+                prefixes.push(v);
+            }
+        }
+    }
+    prefixes
+}
+
+/// Split a `:`-separated list, drop empty and bundle-injected entries, and
+/// de-duplicate while preserving order (so surviving system paths win).
+fn strip_bundle_entries(value: &str, prefixes: &[String]) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    let mut out = Vec::new();
+    for part in value.split(':') {
+        if part.is_empty() {
+            continue;
+        }
+        if prefixes.iter().any(|p| part.starts_with(p.as_str())) {
+            continue;
+        }
+        if seen.insert(part.to_string()) {
+            out.push(part.to_string());
+        }
+    }
+    out
+}
+
+/// Add `key` with a default value only if it isn't already present.
+fn ensure_env(env: &mut Vec<(String, String)>, key: &str, default: &str) {
+    if !env.iter().any(|(k, _)| k == key) {
+        env.push((key.to_string(), default.to_string()));
+    }
+}
+
+/// Build the sanitized key/value pairs to hand a spawned `Command`. When not
+/// packaged this is just the current environment with empty variables dropped;
+/// inside a bundle it additionally strips runtime-injected path entries and
+/// restores XDG defaults.
+fn normalized_command_env() -> Vec<(String, String)> {
+    let packaged = is_appimage() || is_flatpak() || is_snap();
+    let prefixes = bundle_prefixes();
+
+    let mut env: Vec<(String, String)> = Vec::new();
+    for (key, value) in std::env::vars() {
+        if value.is_empty() {
+            continue; // drop empty variables entirely
+        }
+
+        if !packaged {
+            env.push((key, value));
+            continue;
+        }
+
+        match key.as_str() {
+            "PATH" | "LD_LIBRARY_PATH" | "XDG_DATA_DIRS" | "XDG_CONFIG_DIRS"
+            | "GST_PLUGIN_SYSTEM_PATH" | "GST_PLUGIN_PATH" | "GTK_PATH" | "GIO_MODULE_DIR"
+            | "GDK_PIXBUF_MODULE_FILE" => {
+                let cleaned = strip_bundle_entries(&value, &prefixes);
+                if !cleaned.is_empty() {
+                    env.push((key, cleaned.join(":")));
+                }
+            }
+            _ => env.push((key, value)),
+        }
+    }
+
+    if packaged {
+        ensure_env(
+            &mut env,
+            "PATH",
+            "/usr/local/sbin:/usr/local/bin:/usr/sbin:/usr/bin:/sbin:/bin",
+        );
+        ensure_env(&mut env, "XDG_DATA_DIRS", "/usr/local/share:/usr/share");
+        ensure_env(&mut env, "XDG_CONFIG_DIRS", "/etc/xdg");
+    }
+
+    env
+}
+
 // ---------------------------------------------------------------------------
 // Config persistence (~/.littera/desktop.json)
 // ---------------------------------------------------------------------------
 
-#[derive(Serialize, Deserialize, Default, Clone)]
+/// Default recursion depth for the workspace crawl.
+const DEFAULT_MAX_DEPTH: usize = 3;
+
+/// Upper bound on the user-configurable crawl depth, so a runaway value
+/// (combined with a symlink cycle) can't blow the stack.
+const MAX_ALLOWED_SCAN_DEPTH: usize = 10;
+
+fn default_max_depth() -> usize {
+    DEFAULT_MAX_DEPTH
+}
+
+#[derive(Serialize, Deserialize, Clone)]
 struct DesktopConfig {
     recent: Vec<RecentWork>,
     workspace: Option<String>,
+    /// How deep to crawl the workspace looking for works.
+    #[serde(default = "default_max_depth")]
+    max_depth: usize,
+    /// Extra directory-name globs to skip while crawling.
+    #[serde(default)]
+    ignore_globs: Vec<String>,
+}
+
+impl Default for DesktopConfig {
+    fn default() -> Self {
+        DesktopConfig {
+            recent: Vec::new(),
+            workspace: None,
+            max_depth: DEFAULT_MAX_DEPTH,
+            ignore_globs: Vec::new(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -188,6 +452,150 @@ fn record_recent(config: &mut DesktopConfig, path: &str) {
     config.recent.truncate(10);
 }
 
+// ---------------------------------------------------------------------------
+// Logging
+//
+// A single sink for both Tauri-side messages (via the `log` facade) and
+// forwarded sidecar stdout. Records are kept in a capped in-memory ring for
+// `get_recent_logs`, appended to a size-rotated file under ~/.littera/logs/,
+// and streamed to the UI as a `log` Tauri event.
+// ---------------------------------------------------------------------------
+
+/// One structured log line, as surfaced to the in-app viewer.
+#[derive(Serialize, Clone)]
+struct LogRecord {
+    timestamp: u64,
+    level: String,
+    source: String,
+    message: String,
+}
+
+/// How many records the in-memory tail keeps for `get_recent_logs`.
+const LOG_BUFFER_CAP: usize = 1000;
+/// Rotate the active log file once it grows past this size.
+const LOG_FILE_MAX_BYTES: u64 = 1024 * 1024;
+/// How many rotated files to retain (littera.1.log .. littera.N.log).
+const LOG_KEEP_FILES: usize = 5;
+
+static LOG_BUFFER: Mutex<VecDeque<LogRecord>> = Mutex::new(VecDeque::new());
+static APP_HANDLE: OnceLock<tauri::AppHandle> = OnceLock::new();
+
+/// Serializes the rotate-then-append sequence in `append_to_log_file`, which
+/// runs concurrently from every window's sidecar-reader thread plus the
+/// `log::Log` forwarder and would otherwise race rotation and interleave
+/// partial lines from different threads.
+static LOG_FILE_LOCK: Mutex<()> = Mutex::new(());
+
+fn logs_dir() -> PathBuf {
+    config_dir().join("logs")
+}
+
+/// Append one line to the active log file, rotating it if it has grown too big.
+fn append_to_log_file(record: &LogRecord) {
+    let _guard = LOG_FILE_LOCK.lock();
+
+    let dir = logs_dir();
+    if fs::create_dir_all(&dir).is_err() {
+        return;
+    }
+    let active = dir.join("littera.log");
+
+    if let Ok(meta) = fs::metadata(&active) {
+        if meta.len() >= LOG_FILE_MAX_BYTES {
+            rotate_log_files(&dir);
+        }
+    }
+
+    if let Ok(mut file) = fs::OpenOptions::new().create(true).append(true).open(&active) {
+        let _ = writeln!(
+            file,
+            "{} {} [{}] {}",
+            record.timestamp, record.level, record.source, record.message
+        );
+    }
+}
+
+/// Shift littera.log -> littera.1.log -> ... dropping the oldest.
+fn rotate_log_files(dir: &Path) {
+    let _ = fs::remove_file(dir.join(format!("littera.{LOG_KEEP_FILES}.log")));
+    for i in (1..LOG_KEEP_FILES).rev() {
+        let from = dir.join(format!("littera.{i}.log"));
+        let to = dir.join(format!("littera.{}.log", i + 1));
+        let _ = fs::rename(from, to);
+    }
+    let _ = fs::rename(dir.join("littera.log"), dir.join("littera.1.log"));
+}
+
+/// Route a record to the ring buffer, the rotating file, and the UI.
+fn record_log(level: &str, source: &str, message: &str) {
+    let record = LogRecord {
+        timestamp: now_epoch(),
+        level: level.to_string(),
+        source: source.to_string(),
+        message: message.to_string(),
+    };
+
+    if let Ok(mut buffer) = LOG_BUFFER.lock() {
+        buffer.push_back(record.clone());
+        while buffer.len() > LOG_BUFFER_CAP {
+            buffer.pop_front();
+        }
+    }
+
+    append_to_log_file(&record);
+
+    if let Some(app) = APP_HANDLE.get() {
+        let _ = app.emit("log", record);
+    }
+}
+
+/// Minimal `log::Log` implementation forwarding the Tauri side into our sink.
+struct LitteraLogger;
+
+impl log::Log for LitteraLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        record_log(
+            record.level().as_str(),
+            record.target(),
+            &record.args().to_string(),
+        );
+    }
+
+    fn flush(&self) {}
+}
+
+fn init_logging() {
+    static LOGGER: LitteraLogger = LitteraLogger;
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(log::LevelFilter::Info);
+    }
+}
+
+/// Detect a leading level token on a sidecar line, defaulting to INFO. The
+/// line itself is returned unchanged, token included.
+///
+/// Handles both bare prefixes (`ERROR foo`) and Python logging's
+/// `LEVEL:logger:message` form.
+fn parse_sidecar_level(line: &str) -> (&'static str, &str) {
+    let token = line
+        .split(|c: char| c.is_whitespace() || c == ':')
+        .next()
+        .unwrap_or("");
+    let level = match token.to_ascii_uppercase().as_str() {
+        "ERROR" | "CRITICAL" | "FATAL" => "ERROR",
+        "WARN" | "WARNING" => "WARN",
+        "INFO" => "INFO",
+        "DEBUG" => "DEBUG",
+        "TRACE" => "TRACE",
+        _ => return ("INFO", line),
+    };
+    (level, line)
+}
+
 // ---------------------------------------------------------------------------
 // Picker data structures
 // ---------------------------------------------------------------------------
@@ -196,35 +604,187 @@ fn record_recent(config: &mut DesktopConfig, path: &str) {
 struct WorkEntry {
     name: String,
     path: String,
+    /// Path relative to the workspace root, for display of nested works.
+    rel_path: String,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 struct PickerData {
     recent: Vec<RecentWork>,
     workspace_works: Vec<WorkEntry>,
     workspace: Option<String>,
 }
 
-/// Scan immediate children of `dir` for Littera works.
-fn scan_workspace(dir: &Path) -> Vec<WorkEntry> {
+/// Assemble picker data from the persisted config, scanning the workspace.
+fn build_picker_data(config: DesktopConfig) -> PickerData {
+    let globs = compile_ignore_globs(&config.ignore_globs);
+    let workspace_works = config
+        .workspace
+        .as_ref()
+        .map(|ws| scan_workspace(Path::new(ws), config.max_depth, &globs))
+        .unwrap_or_default();
+
+    PickerData {
+        recent: config.recent,
+        workspace_works,
+        workspace: config.workspace,
+    }
+}
+
+/// Cap on results so a huge tree can't trigger a runaway scan.
+const MAX_WORKSPACE_RESULTS: usize = 500;
+
+/// Compile user-supplied ignore globs, discarding any that fail to parse.
+fn compile_ignore_globs(patterns: &[String]) -> Vec<glob::Pattern> {
+    patterns
+        .iter()
+        .filter_map(|p| glob::Pattern::new(p).ok())
+        .collect()
+}
+
+/// Whether a directory name should be skipped during the crawl.
+fn is_ignored_dir(name: &str, user_globs: &[glob::Pattern]) -> bool {
+    // Dot-directories (covers .git and .venv) and common heavy dirs.
+    if name.starts_with('.') || name == "node_modules" {
+        return true;
+    }
+    user_globs.iter().any(|g| g.matches(name))
+}
+
+/// Crawl `root` recursively (up to `max_depth` levels below the root) for
+/// Littera works, honouring ignore rules and the global result cap.
+fn scan_workspace(root: &Path, max_depth: usize, user_globs: &[glob::Pattern]) -> Vec<WorkEntry> {
     let mut works = Vec::new();
-    if let Ok(entries) = fs::read_dir(dir) {
-        for entry in entries.flatten() {
-            let child = entry.path();
-            if child.is_dir() && is_littera_work(&child) {
-                let name = child
-                    .file_name()
-                    .map(|n| n.to_string_lossy().to_string())
-                    .unwrap_or_default();
-                works.push(WorkEntry {
-                    name,
-                    path: child.to_string_lossy().to_string(),
-                });
+    // Tracks canonicalized directories already descended into, so a symlink
+    // cycle can't turn the depth-bounded recursion into unbounded recursion.
+    let mut visited = std::collections::HashSet::new();
+    if let Ok(canonical_root) = fs::canonicalize(root) {
+        visited.insert(canonical_root);
+    }
+    crawl_workspace(root, root, 0, max_depth, user_globs, &mut works, &mut visited);
+    works.sort_by(|a, b| a.rel_path.cmp(&b.rel_path));
+    works
+}
+
+fn crawl_workspace(
+    root: &Path,
+    dir: &Path,
+    depth: usize,
+    max_depth: usize,
+    user_globs: &[glob::Pattern],
+    out: &mut Vec<WorkEntry>,
+    visited: &mut std::collections::HashSet<PathBuf>,
+) {
+    if out.len() >= MAX_WORKSPACE_RESULTS {
+        return;
+    }
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return, // directory vanished or is unreadable; skip it
+    };
+    for entry in entries.flatten() {
+        if out.len() >= MAX_WORKSPACE_RESULTS {
+            return;
+        }
+        let child = entry.path();
+        if !child.is_dir() {
+            continue;
+        }
+        let name = child
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if is_ignored_dir(&name, user_globs) {
+            continue;
+        }
+        if is_littera_work(&child) {
+            let rel_path = child
+                .strip_prefix(root)
+                .unwrap_or(&child)
+                .to_string_lossy()
+                .to_string();
+            out.push(WorkEntry {
+                name,
+                path: child.to_string_lossy().to_string(),
+                rel_path,
+            });
+            continue; // don't recurse inside a work
+        }
+        // Descend until we reach the configured depth (children are depth 0),
+        // but only into directories we haven't already visited via some other
+        // symlink path.
+        if depth + 1 < max_depth {
+            let unvisited = match fs::canonicalize(&child) {
+                Ok(canonical) => visited.insert(canonical),
+                Err(_) => true, // can't canonicalize; fall back to descending
+            };
+            if unvisited {
+                crawl_workspace(root, &child, depth + 1, max_depth, user_globs, out, visited);
             }
         }
     }
-    works.sort_by(|a, b| a.name.cmp(&b.name));
-    works
+}
+
+// ---------------------------------------------------------------------------
+// Workspace watching
+//
+// Best-effort, rust-analyzer style: debounce bursts of create/remove/rename
+// events, re-scan, and push a `workspace-changed` event to the picker. Never
+// panics if the directory vanishes; torn down and replaced when the workspace
+// changes.
+// ---------------------------------------------------------------------------
+
+/// Coalesce filesystem events arriving within this window into one rescan.
+const WORKSPACE_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Owns the active watcher; dropping it stops the backing thread (the notify
+/// handler's channel sender goes away, so the debounce loop's `recv` ends).
+struct WorkspaceWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+struct WatcherState(Mutex<Option<WorkspaceWatcher>>);
+
+/// Begin watching `dir`, emitting `workspace-changed` when its works change.
+fn start_workspace_watcher(app: &tauri::AppHandle, dir: &Path) -> Option<WorkspaceWatcher> {
+    let (tx, rx) = mpsc::channel::<()>();
+
+    let mut watcher = RecommendedWatcher::new(
+        move |res: notify::Result<notify::Event>| {
+            if let Ok(event) = res {
+                // Only creation/removal/rename of children changes the picker.
+                if matches!(
+                    event.kind,
+                    EventKind::Create(_)
+                        | EventKind::Remove(_)
+                        | EventKind::Modify(notify::event::ModifyKind::Name(_))
+                ) {
+                    let _ = tx.send(());
+                }
+            }
+        },
+        notify::Config::default(),
+    )
+    .ok()?;
+
+    // Watch the full subtree so works nested several levels down (matching
+    // scan_workspace's recursive crawl) still trigger a rescan; a vanished
+    // directory is not fatal.
+    if watcher.watch(dir, RecursiveMode::Recursive).is_err() {
+        return None;
+    }
+
+    let app = app.clone();
+    thread::spawn(move || {
+        // Block until the first event, then swallow the rest of the burst.
+        while rx.recv().is_ok() {
+            while rx.recv_timeout(WORKSPACE_DEBOUNCE).is_ok() {}
+            let data = build_picker_data(load_config());
+            let _ = app.emit("workspace-changed", data);
+        }
+    });
+
+    Some(WorkspaceWatcher { _watcher: watcher })
 }
 
 // ---------------------------------------------------------------------------
@@ -232,10 +792,10 @@ fn scan_workspace(dir: &Path) -> Vec<WorkEntry> {
 // ---------------------------------------------------------------------------
 
 #[tauri::command]
-fn sidecar_port(state: tauri::State<SidecarState>) -> Result<u16, String> {
+fn sidecar_port(window: WebviewWindow, state: tauri::State<SidecarState>) -> Result<u16, String> {
     let guard = state.0.lock().map_err(|e| e.to_string())?;
     guard
-        .as_ref()
+        .get(window.label())
         .map(|s| s.port)
         .ok_or_else(|| "Sidecar not ready".to_string())
 }
@@ -248,18 +808,7 @@ fn open_devtools(window: WebviewWindow) {
 /// Load picker screen data: recent works + workspace contents.
 #[tauri::command]
 fn get_picker_data() -> PickerData {
-    let config = load_config();
-    let workspace_works = config
-        .workspace
-        .as_ref()
-        .map(|ws| scan_workspace(Path::new(ws)))
-        .unwrap_or_default();
-
-    PickerData {
-        recent: config.recent,
-        workspace_works,
-        workspace: config.workspace,
-    }
+    build_picker_data(load_config())
 }
 
 /// Open native OS folder dialog via rfd.
@@ -272,22 +821,43 @@ fn pick_folder() -> Option<String> {
 
 /// Set the workspace directory, save to config, return refreshed picker data.
 #[tauri::command]
-fn set_workspace(path: String) -> PickerData {
+fn set_workspace(
+    path: String,
+    app: tauri::AppHandle,
+    watcher: tauri::State<WatcherState>,
+) -> PickerData {
     let mut config = load_config();
     config.workspace = Some(path.clone());
     save_config(&config);
 
-    let workspace_works = scan_workspace(Path::new(&path));
-    PickerData {
-        recent: config.recent,
-        workspace_works,
-        workspace: config.workspace,
+    // Replace any existing watcher with one bound to the new workspace.
+    if let Ok(mut guard) = watcher.0.lock() {
+        *guard = None; // tear the old watcher down before starting a new one
+        *guard = start_workspace_watcher(&app, Path::new(&path));
     }
+
+    build_picker_data(config)
+}
+
+/// Update the workspace crawl's max depth and ignore globs, save to config,
+/// and return refreshed picker data reflecting the new scan.
+#[tauri::command]
+fn set_workspace_scan_config(max_depth: usize, ignore_globs: Vec<String>) -> PickerData {
+    let mut config = load_config();
+    config.max_depth = max_depth.min(MAX_ALLOWED_SCAN_DEPTH);
+    config.ignore_globs = ignore_globs;
+    save_config(&config);
+
+    build_picker_data(config)
 }
 
 /// Validate that path is a Littera work, spawn sidecar, record in recents.
 #[tauri::command]
-fn open_work(path: String, state: tauri::State<SidecarState>) -> Result<u16, String> {
+fn open_work(
+    path: String,
+    window: WebviewWindow,
+    state: tauri::State<SidecarState>,
+) -> Result<u16, String> {
     let work_path = Path::new(&path);
 
     if !is_littera_work(work_path) {
@@ -297,20 +867,24 @@ fn open_work(path: String, state: tauri::State<SidecarState>) -> Result<u16, Str
         ));
     }
 
-    // Kill existing sidecar (drop replaces the old one)
-    {
+    // Drop any sidecar already bound to this window before replacing it. The
+    // removed Sidecar is dropped outside the lock, since Drop runs up to
+    // SIDECAR_SHUTDOWN_GRACE's worth of shutdown polling and would otherwise
+    // stall every other window's sidecar_port/open_work/close_work calls.
+    let dying = {
         let mut guard = state.0.lock().map_err(|e| e.to_string())?;
-        *guard = None;
-    }
+        guard.remove(window.label())
+    };
+    drop(dying);
 
     // Spawn new sidecar
     let sidecar = spawn_sidecar(&path)?;
     let port = sidecar.port;
-    eprintln!("Sidecar ready on port {port} for {path}");
+    log::info!("Sidecar ready on port {port} for {path}");
 
     {
         let mut guard = state.0.lock().map_err(|e| e.to_string())?;
-        *guard = Some(sidecar);
+        guard.insert(window.label().to_string(), sidecar);
     }
 
     // Record in recents
@@ -321,11 +895,56 @@ fn open_work(path: String, state: tauri::State<SidecarState>) -> Result<u16, Str
     Ok(port)
 }
 
-/// Close the current work, stopping the sidecar.
+/// Open a work in a brand-new window, leaving the current one untouched.
+#[tauri::command]
+fn open_work_window(path: String, app: tauri::AppHandle) -> Result<String, String> {
+    let work_path = Path::new(&path);
+    if !is_littera_work(work_path) {
+        return Err(format!(
+            "Not a Littera work (no .littera/ directory): {}",
+            path
+        ));
+    }
+
+    // Window labels must be unique; an atomic counter guarantees that even
+    // across requests landing within the same second, unlike now_epoch().
+    static NEXT_WINDOW_ID: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    let id = NEXT_WINDOW_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let label = format!("work-{id}");
+
+    // Stash the target path for the new window to pick up once it loads and
+    // calls `take_pending_work_path`; a fresh window otherwise has no way to
+    // learn which work it's supposed to open.
+    if let Ok(mut pending) = PENDING_WINDOW_PATHS.lock() {
+        pending.push((label.clone(), path));
+    }
+
+    WebviewWindowBuilder::new(&app, &label, WebviewUrl::default())
+        .title("Littera")
+        .build()
+        .map_err(|e| format!("Failed to open window: {e}"))?;
+
+    Ok(label)
+}
+
+/// Retrieve and clear the path stashed for this window by `open_work_window`.
 #[tauri::command]
-fn close_work(state: tauri::State<SidecarState>) -> Result<(), String> {
-    let mut guard = state.0.lock().map_err(|e| e.to_string())?;
-    *guard = None; // Drop triggers sidecar shutdown
+fn take_pending_work_path(window: WebviewWindow) -> Option<String> {
+    let mut pending = PENDING_WINDOW_PATHS.lock().ok()?;
+    let pos = pending.iter().position(|(label, _)| label == window.label())?;
+    Some(pending.remove(pos).1)
+}
+
+/// Close the work bound to this window, stopping its sidecar.
+#[tauri::command]
+fn close_work(window: WebviewWindow, state: tauri::State<SidecarState>) -> Result<(), String> {
+    // Drop the removed Sidecar outside the lock (see open_work) so its
+    // shutdown grace period doesn't stall other windows' sidecar access.
+    let dying = {
+        let mut guard = state.0.lock().map_err(|e| e.to_string())?;
+        guard.remove(window.label())
+    };
+    drop(dying); // Drop triggers sidecar shutdown
     Ok(())
 }
 
@@ -336,6 +955,8 @@ fn init_work(path: String) -> Result<(), String> {
 
     let output = Command::new(&python)
         .args(["-m", "littera", "init", &path])
+        .env_clear()
+        .envs(normalized_command_env())
         .output()
         .map_err(|e| format!("Failed to run littera init: {e}"))?;
 
@@ -347,6 +968,221 @@ fn init_work(path: String) -> Result<(), String> {
     Ok(())
 }
 
+// ---------------------------------------------------------------------------
+// OS integration (reveal / open externally)
+// ---------------------------------------------------------------------------
+
+/// Reveal a work folder in the native file manager, selecting the item.
+#[tauri::command]
+fn reveal_work(path: String) -> Result<(), String> {
+    let target = Path::new(&path);
+    if !target.exists() {
+        return Err(format!("Path does not exist: {path}"));
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        Command::new("open")
+            .args(["-R", &path])
+            .spawn()
+            .map_err(|e| format!("Failed to reveal in Finder: {e}"))?;
+        Ok(())
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        Command::new("explorer")
+            .arg(format!("/select,{path}"))
+            .spawn()
+            .map_err(|e| format!("Failed to reveal in Explorer: {e}"))?;
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        // Prefer the freedesktop FileManager1 interface for a true "select the
+        // item"; fall back to opening the parent directory with xdg-open.
+        let uri = format!("file://{}", percent_encode_path(&path));
+        let selected = Command::new("dbus-send")
+            .args([
+                "--session",
+                "--dest=org.freedesktop.FileManager1",
+                "--type=method_call",
+                "/org/freedesktop/FileManager1",
+                "org.freedesktop.FileManager1.ShowItems",
+                &format!("array:string:{uri}"),
+                "string:",
+            ])
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false);
+        if selected {
+            return Ok(());
+        }
+
+        let parent = target.parent().unwrap_or(target);
+        Command::new("xdg-open")
+            .arg(parent)
+            .spawn()
+            .map_err(|e| format!("Failed to open file manager: {e}"))?;
+        Ok(())
+    }
+}
+
+/// Open a file or folder with the OS default handler.
+#[tauri::command]
+fn open_path_external(path: String) -> Result<(), String> {
+    if !Path::new(&path).exists() {
+        return Err(format!("Path does not exist: {path}"));
+    }
+
+    #[cfg(target_os = "macos")]
+    let mut command = {
+        let mut c = Command::new("open");
+        c.arg(&path);
+        c
+    };
+
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut c = Command::new("explorer");
+        c.arg(&path);
+        c
+    };
+
+    #[cfg(target_os = "linux")]
+    let mut command = {
+        let mut c = Command::new("xdg-open");
+        c.arg(&path);
+        c
+    };
+
+    command
+        .spawn()
+        .map_err(|e| format!("Failed to open {path}: {e}"))?;
+    Ok(())
+}
+
+/// Return the tail of the in-memory log buffer as structured records.
+#[tauri::command]
+fn get_recent_logs(limit: usize) -> Vec<LogRecord> {
+    let buffer = match LOG_BUFFER.lock() {
+        Ok(buffer) => buffer,
+        Err(_) => return Vec::new(),
+    };
+    let start = buffer.len().saturating_sub(limit);
+    buffer.iter().skip(start).cloned().collect()
+}
+
+// ---------------------------------------------------------------------------
+// Diagnostics
+// ---------------------------------------------------------------------------
+
+/// Versions of every moving part, for an About/Support panel.
+#[derive(Serialize)]
+struct Diagnostics {
+    python_path: String,
+    python_version: String,
+    python_source: String,
+    littera_version: String,
+    postgres_version: String,
+    app_version: String,
+    tauri_version: String,
+    os: String,
+    arch: String,
+}
+
+/// Run a subprocess with a deadline, returning its trimmed output or `None`.
+/// A process that's still running when the deadline passes is killed, same
+/// as `spawn_sidecar`'s kill-on-timeout discipline, so a wedged probe can't
+/// leak a thread and a live subprocess.
+fn probe_output(program: &str, args: &[&str], timeout: Duration) -> Option<String> {
+    let mut child = Command::new(program)
+        .args(args)
+        .env_clear()
+        .envs(normalized_command_env())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .ok()?;
+
+    let mut stdout_pipe = child.stdout.take();
+    let mut stderr_pipe = child.stderr.take();
+    let child = std::sync::Arc::new(Mutex::new(child));
+    let waited_child = std::sync::Arc::clone(&child);
+
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        use std::io::Read;
+        let mut stdout_buf = Vec::new();
+        let mut stderr_buf = Vec::new();
+        if let Some(mut s) = stdout_pipe.take() {
+            let _ = s.read_to_end(&mut stdout_buf);
+        }
+        if let Some(mut s) = stderr_pipe.take() {
+            let _ = s.read_to_end(&mut stderr_buf);
+        }
+        let status = waited_child.lock().ok().and_then(|mut c| c.wait().ok());
+        let _ = tx.send((status, stdout_buf, stderr_buf));
+    });
+
+    match rx.recv_timeout(timeout) {
+        Ok((Some(status), stdout, stderr)) if status.success() => {
+            // Some tools print `--version` to stderr; fall back to it.
+            let stdout = String::from_utf8_lossy(&stdout);
+            let text = if stdout.trim().is_empty() {
+                String::from_utf8_lossy(&stderr).to_string()
+            } else {
+                stdout.to_string()
+            };
+            let text = text.trim();
+            if text.is_empty() {
+                None
+            } else {
+                Some(text.to_string())
+            }
+        }
+        Ok(_) => None,
+        Err(_) => {
+            // Timed out: the reader thread is still blocked reading the
+            // child's output; kill the child so its pipes close and neither
+            // it nor the thread leaks.
+            if let Ok(mut c) = child.lock() {
+                let _ = c.kill();
+                let _ = c.wait();
+            }
+            None
+        }
+    }
+}
+
+/// Best-effort Postgres server version via the binaries on PATH.
+fn probe_postgres(timeout: Duration) -> Option<String> {
+    probe_output("postgres", &["--version"], timeout)
+        .or_else(|| probe_output("pg_ctl", &["--version"], timeout))
+}
+
+/// Gather component versions into a serializable support report.
+#[tauri::command]
+fn diagnostics(app: tauri::AppHandle) -> Diagnostics {
+    let python = find_python();
+    let timeout = Duration::from_secs(5);
+    let unknown = || "unknown".to_string();
+
+    Diagnostics {
+        python_version: probe_output(&python, &["--version"], timeout).unwrap_or_else(unknown),
+        littera_version: probe_output(&python, &["-m", "littera", "--version"], timeout)
+            .unwrap_or_else(unknown),
+        postgres_version: probe_postgres(timeout).unwrap_or_else(unknown),
+        python_source: python_source(),
+        python_path: python,
+        app_version: app.package_info().version.to_string(),
+        tauri_version: tauri::VERSION.to_string(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // App entry point
 // ---------------------------------------------------------------------------
@@ -354,8 +1190,24 @@ fn init_work(path: String) -> Result<(), String> {
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
-        .manage(SidecarState(Mutex::new(None)))
+        .manage(SidecarState(Mutex::new(HashMap::new())))
+        .manage(WatcherState(Mutex::new(None)))
         .setup(|app| {
+            // Bring up logging and let the sink emit events to this app.
+            init_logging();
+            let _ = APP_HANDLE.set(app.handle().clone());
+
+            // Resume watching a previously-set workspace on launch.
+            let config = load_config();
+            if let Some(ws) = config.workspace.as_ref() {
+                let watcher = start_workspace_watcher(&app.handle().clone(), Path::new(ws));
+                if let Some(state) = app.try_state::<WatcherState>() {
+                    if let Ok(mut guard) = state.0.lock() {
+                        *guard = watcher;
+                    }
+                }
+            }
+
             // Open devtools: invoke("open_devtools") from JS console,
             // or set LITTERA_DEVTOOLS=1 to auto-open on startup.
             #[cfg(debug_assertions)]
@@ -373,12 +1225,28 @@ pub fn run() {
             get_picker_data,
             pick_folder,
             set_workspace,
+            set_workspace_scan_config,
             open_work,
+            open_work_window,
+            take_pending_work_path,
             close_work,
             init_work,
+            diagnostics,
+            reveal_work,
+            open_path_external,
+            get_recent_logs,
         ])
-        .run(tauri::generate_context!())
-        .expect("error running Littera");
+        .build(tauri::generate_context!())
+        .expect("error running Littera")
+        .run(|app_handle, event| {
+            // Shut down every window's sidecar in parallel on exit, rather
+            // than leaving it to the SidecarState HashMap's sequential Drop.
+            if let tauri::RunEvent::ExitRequested { .. } = event {
+                if let Some(state) = app_handle.try_state::<SidecarState>() {
+                    shutdown_all_sidecars(&state);
+                }
+            }
+        });
 }
 
 fn main() {